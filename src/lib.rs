@@ -1,7 +1,7 @@
 use anyhow::anyhow;
 use printpdf::{
-    image_crate::codecs::jpeg::JpegDecoder, Image, ImageTransform, Mm, PdfDocument,
-    PdfDocumentReference,
+    Color, ColorBits, ColorSpace, Image, ImageTransform, ImageXObject, Line, Mm, PdfDocument,
+    PdfDocumentReference, PdfLayerReference, Point, Px, Rgb,
 };
 use reqwest::{
     blocking,
@@ -11,11 +11,120 @@ use reqwest::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
-    fs::File,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    fmt,
+    fs::{self, File},
+    hash::{Hash, Hasher},
     io::{BufRead, BufReader, BufWriter, Cursor, Read},
-    path::Path,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
 };
 
+/// Why a single card didn't make it into the output.
+#[derive(Debug)]
+pub enum ProxyError {
+    /// A CSV or txt-deck line didn't match the expected shape.
+    MalformedRow { line: String },
+    /// Scryfall had no match for this name, or returned no usable art.
+    CardNotFound { name: String },
+    /// The art URL couldn't be downloaded.
+    ImageFetch { url: String, status: String },
+    /// `make_image` couldn't decode the fetched bytes.
+    UnsupportedFormat { detected: String },
+    /// The requested `lang` printing had no art, so English art was used.
+    LocalizedFallback { name: String, lang: String },
+}
+
+impl fmt::Display for ProxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MalformedRow { line } => write!(f, "malformed row: {line}"),
+            Self::CardNotFound { name } => write!(f, "no Scryfall match for \"{name}\""),
+            Self::ImageFetch { url, status } => write!(f, "failed to fetch {url}: {status}"),
+            Self::UnsupportedFormat { detected } => {
+                write!(f, "unsupported image format: {detected}")
+            }
+            Self::LocalizedFallback { name, lang } => write!(
+                f,
+                "no {lang} art for \"{name}\"; used English art instead"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProxyError {}
+
+/// One card that was dropped from the output, and why.
+#[derive(Debug)]
+pub struct CardFailure {
+    pub card_name: String,
+    pub error: ProxyError,
+}
+
+/// Accumulates per-card failures and notices across a run so callers can
+/// report exactly which cards were dropped instead of silently shipping a
+/// short deck.
+///
+/// Failures and notices are tracked separately: a failure means the card
+/// is missing from the output, while a notice (e.g. a localized-art
+/// fallback) is purely informational about a card that *is* in the
+/// output. Only failures affect `is_success()`.
+#[derive(Debug, Default)]
+pub struct ProxyReport {
+    failures: Vec<CardFailure>,
+    notices: Vec<CardFailure>,
+}
+
+impl ProxyReport {
+    /// Records a card that was dropped from the output.
+    pub fn push(&mut self, failure: CardFailure) {
+        self.failures.push(failure);
+    }
+
+    /// Records an informational note about a card that's still in the
+    /// output, such as a localized-art fallback.
+    pub fn push_notice(&mut self, notice: CardFailure) {
+        self.notices.push(notice);
+    }
+
+    pub fn failures(&self) -> &[CardFailure] {
+        &self.failures
+    }
+
+    pub fn notices(&self) -> &[CardFailure] {
+        &self.notices
+    }
+
+    /// True if every requested card made it into the output. Notices
+    /// don't affect this: a fully-generated deck is a success even if
+    /// some cards fell back to English art.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for ProxyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.failures.is_empty() && self.notices.is_empty() {
+            return writeln!(f, "all cards processed successfully");
+        }
+        if !self.failures.is_empty() {
+            writeln!(f, "{} card(s) dropped from the output:", self.failures.len())?;
+            for failure in &self.failures {
+                writeln!(f, "  - {}: {}", failure.card_name, failure.error)?;
+            }
+        }
+        if !self.notices.is_empty() {
+            writeln!(f, "{} notice(s):", self.notices.len())?;
+            for notice in &self.notices {
+                writeln!(f, "  - {}: {}", notice.card_name, notice.error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// These are the fields expected in a CSV row
 /// used for proxy generation.
 #[derive(Serialize, Deserialize, Debug)]
@@ -23,12 +132,54 @@ struct DeckCsvRow {
     count: usize,
     card_name: String,
     image_url: String,
+    /// Art for a double-faced/transform card's second face, if it has one.
+    #[serde(default)]
+    back_image_url: Option<String>,
+}
+
+/// A card's art, split out for double-faced/transform cards whose second
+/// face has art of its own.
+struct CardArtUrls {
+    front: String,
+    back: Option<String>,
+}
+
+/// Configurable geometry for a page layout: how big cards are drawn, how
+/// much space separates them, and whether to draw guides to cut them
+/// apart. Exposed so callers aren't stuck with one hard-coded card size
+/// or margin.
+#[derive(Debug, Clone)]
+pub struct LayoutConfig {
+    pub card_width_mm: f32,
+    pub card_height_mm: f32,
+    pub margin_between_cards_mm: f32,
+    /// Draw short crop marks at each card's corners, extending into the
+    /// surrounding margin, to guide a straight cut.
+    pub draw_crop_marks: bool,
+    /// Draw full-length cut lines across the page at every internal gap
+    /// between cards, for cutting on a guillotine.
+    pub draw_cut_grid: bool,
 }
 
-/// Struct for creating PDFs with American-sized MTG cards
-/// arranged 3x3 on normal printer paper.
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            // Undersized by 2 mm from the true MTG card size so cards fit
+            // better in a card sleeve.
+            card_width_mm: 61.5,
+            card_height_mm: 86.9,
+            margin_between_cards_mm: 1.,
+            draw_crop_marks: false,
+            draw_cut_grid: false,
+        }
+    }
+}
+
+/// Struct for creating PDFs with MTG-sized cards arranged 3x3 on normal
+/// printer paper.
 pub struct ProxyPdf {
     pdf: PdfDocumentReference,
+    layout: LayoutConfig,
 }
 
 impl ProxyPdf {
@@ -36,27 +187,38 @@ impl ProxyPdf {
     const PAGE_HEIGHT_MM: f32 = 279.;
     const PAGE_WIDTH_MM: f32 = 210.;
 
-    /// Dimensions of an MTG card. Undersized by 2 mm so they fit better in a card sleeve
-    const CARD_HEIGHT_MM: f32 = 86.9;
-    const CARD_WIDTH_MM: f32 = 61.5;
-
     /// Pixel density in images
     const DPI: f32 = 300.;
 
-    /// How much space is on the document's borders
-    const HEIGHT_MARGIN_MM: f32 =
-        (Self::PAGE_HEIGHT_MM - (3. * Self::CARD_HEIGHT_MM) - (2. * Self::MARGIN_BETWEEN_CARDS_MM))
-            / 2.;
-    const WIDTH_MARGIN_MM: f32 =
-        (Self::PAGE_WIDTH_MM - (3. * Self::CARD_WIDTH_MM) - (2. * Self::MARGIN_BETWEEN_CARDS_MM))
-            / 2.;
+    /// How far a crop mark extends into the surrounding margin or
+    /// inter-card gap.
+    const CROP_MARK_LEN_MM: f32 = 3.;
+
+    /// Creates a new pdf using the given layout. Remember to call
+    /// `.save` when finished.
+    pub fn new(layout: LayoutConfig) -> Self {
+        Self {
+            pdf: PdfDocument::empty("MTG deck proxy"),
+            layout,
+        }
+    }
 
-    /// How much space is between cards
-    const MARGIN_BETWEEN_CARDS_MM: f32 = 1.;
+    /// How much space is on the document's left/right borders, given the
+    /// configured card width and inter-card margin.
+    fn width_margin_mm(&self) -> f32 {
+        (Self::PAGE_WIDTH_MM
+            - (3. * self.layout.card_width_mm)
+            - (2. * self.layout.margin_between_cards_mm))
+            / 2.
+    }
 
-    /// Creates a new pdf. Remember to call `.save` when finished.
-    pub fn new() -> Self {
-        Self::default()
+    /// How much space is on the document's top/bottom borders, given the
+    /// configured card height and inter-card margin.
+    fn height_margin_mm(&self) -> f32 {
+        (Self::PAGE_HEIGHT_MM
+            - (3. * self.layout.card_height_mm)
+            - (2. * self.layout.margin_between_cards_mm))
+            / 2.
     }
 
     /// Saves the PDF to the given file path. Use a `.pdf` file ending.
@@ -66,100 +228,468 @@ impl ProxyPdf {
             .save(&mut BufWriter::new(File::create(output_file)?))?)
     }
 
-    /// Builds an MTG proxy from the iterator. This assumes every iterator
-    /// item is the bytes of an image.
-    pub fn gen_pdf<'a, R: Read>(&self, images: impl Iterator<Item = R> + 'a) -> anyhow::Result<()> {
+    /// Builds an MTG proxy from the iterator. Each item is a card's name,
+    /// its front art, and (when the card has a second face) that face's
+    /// art. Cards whose front art can't be decoded are dropped from the
+    /// layout (no empty slot left behind) and recorded in `report`
+    /// instead of aborting the whole run.
+    ///
+    /// When `duplex_default_back` is `Some`, a back page is emitted after
+    /// every front page: cards with a second face get it, and single-
+    /// faced cards get the given uniform card-back image. Back cards are
+    /// placed in mirrored column order so the front and back align when
+    /// printed double-sided and cut.
+    pub fn gen_pdf<'a, R: Read>(
+        &self,
+        images: impl Iterator<Item = (String, R, Option<R>)> + 'a,
+        report: &mut ProxyReport,
+        duplex_default_back: Option<&[u8]>,
+    ) -> anyhow::Result<()> {
         let mut pages_this_doc = 0;
         let mut cards_this_page = 8;
         let mut current_layer = None;
+        let mut page_backs: Vec<(usize, usize, String, Option<Vec<u8>>)> = Vec::new();
+
+        let mut buf = Vec::new();
+        for (card_name, front_bytes, back_bytes) in images {
+            let image = match self.make_image(front_bytes, &mut buf) {
+                Ok(image) => image,
+                Err(e) => {
+                    report.push(CardFailure {
+                        card_name,
+                        error: ProxyError::UnsupportedFormat {
+                            detected: e.to_string(),
+                        },
+                    });
+                    continue;
+                }
+            };
 
-        for image_bytes in images {
             cards_this_page = (cards_this_page + 1) % 9;
             let row = cards_this_page / 3;
             let col = cards_this_page % 3;
 
             if row == 0 && col == 0 {
+                if duplex_default_back.is_some() && pages_this_doc > 0 {
+                    self.emit_back_page(pages_this_doc, &page_backs, duplex_default_back.unwrap(), &mut buf, report)?;
+                    page_backs.clear();
+                }
                 pages_this_doc += 1;
                 let (page_idx, layer_idx) = self.pdf.add_page(
                     Mm(Self::PAGE_WIDTH_MM),
                     Mm(Self::PAGE_HEIGHT_MM),
                     format!("page{pages_this_doc}"),
                 );
-                current_layer = Some(self.pdf.get_page(page_idx).get_layer(layer_idx));
+                let layer = self.pdf.get_page(page_idx).get_layer(layer_idx);
+                self.draw_guides(&layer);
+                current_layer = Some(layer);
             }
 
-            let image = Image::try_from(JpegDecoder::new(image_bytes)?)?;
-            let height_mm = Mm::from(image.image.height.into_pt(Self::DPI)).0;
-            let width_mm = Mm::from(image.image.width.into_pt(Self::DPI)).0;
-
-            let height_scale = Self::CARD_HEIGHT_MM / height_mm;
-            let width_scale = Self::CARD_WIDTH_MM / width_mm;
-            let (col32, row32) = (col as f32, row as f32);
-
-            let col_cardspace = f32::ceil(col32 / 1.) * Self::MARGIN_BETWEEN_CARDS_MM;
-            let row_cardspace = f32::ceil(row32 / 1.) * Self::MARGIN_BETWEEN_CARDS_MM;
+            if duplex_default_back.is_some() {
+                let back_bytes = match back_bytes {
+                    Some(mut reader) => {
+                        let mut owned = Vec::new();
+                        reader.read_to_end(&mut owned)?;
+                        Some(owned)
+                    }
+                    None => None,
+                };
+                page_backs.push((row, col, card_name, back_bytes));
+            }
 
-            image.add_to_layer(
+            self.add_card_to_layer(
+                image,
                 current_layer
                     .as_ref()
                     .expect("Prev steps should guarantee layer is present")
                     .clone(),
-                ImageTransform {
-                    translate_x: Some(Mm(col32 * Self::CARD_WIDTH_MM
-                        + col_cardspace
-                        + Self::WIDTH_MARGIN_MM)),
-                    translate_y: Some(Mm(row32 * Self::CARD_HEIGHT_MM
-                        + row_cardspace
-                        + Self::HEIGHT_MARGIN_MM)),
-                    scale_x: Some(width_scale),
-                    scale_y: Some(height_scale),
-                    dpi: Some(Self::DPI),
-                    rotate: None,
-                },
+                row,
+                col,
             );
         }
 
+        if let Some(default_back) = duplex_default_back {
+            if !page_backs.is_empty() {
+                self.emit_back_page(pages_this_doc, &page_backs, default_back, &mut buf, report)?;
+            }
+        }
+
         Ok(())
     }
+
+    /// Millimeter coordinates of a card's bottom-left corner at grid
+    /// position `(row, col)`, in the page's coordinate space.
+    fn card_origin_mm(&self, row: usize, col: usize) -> (f32, f32) {
+        let (col32, row32) = (col as f32, row as f32);
+        let x = col32 * (self.layout.card_width_mm + self.layout.margin_between_cards_mm)
+            + self.width_margin_mm();
+        let y = row32 * (self.layout.card_height_mm + self.layout.margin_between_cards_mm)
+            + self.height_margin_mm();
+        (x, y)
+    }
+
+    /// Places a decoded card image at grid position `(row, col)` on
+    /// `layer`, scaled to fill one card slot.
+    fn add_card_to_layer(&self, image: Image, layer: PdfLayerReference, row: usize, col: usize) {
+        let height_mm = Mm::from(image.image.height.into_pt(Self::DPI)).0;
+        let width_mm = Mm::from(image.image.width.into_pt(Self::DPI)).0;
+
+        let height_scale = self.layout.card_height_mm / height_mm;
+        let width_scale = self.layout.card_width_mm / width_mm;
+        let (x, y) = self.card_origin_mm(row, col);
+
+        image.add_to_layer(
+            layer,
+            ImageTransform {
+                translate_x: Some(Mm(x)),
+                translate_y: Some(Mm(y)),
+                scale_x: Some(width_scale),
+                scale_y: Some(height_scale),
+                dpi: Some(Self::DPI),
+                rotate: None,
+            },
+        );
+    }
+
+    /// Draws cutting guides for the 3x3 grid on `layer`, per the
+    /// layout's `draw_crop_marks`/`draw_cut_grid` settings. The grid is
+    /// fixed geometry, so guides can be drawn as soon as the page exists
+    /// rather than waiting to see which slots end up with cards.
+    fn draw_guides(&self, layer: &PdfLayerReference) {
+        if !self.layout.draw_crop_marks && !self.layout.draw_cut_grid {
+            return;
+        }
+
+        layer.set_outline_thickness(0.25);
+        layer.set_outline_color(Color::Rgb(Rgb::new(0., 0., 0., None)));
+
+        if self.layout.draw_crop_marks {
+            for row in 0..3 {
+                for col in 0..3 {
+                    let (x0, y0) = self.card_origin_mm(row, col);
+                    let x1 = x0 + self.layout.card_width_mm;
+                    let y1 = y0 + self.layout.card_height_mm;
+                    self.draw_crop_mark(layer, x0, y0, -1., -1.);
+                    self.draw_crop_mark(layer, x0, y1, -1., 1.);
+                    self.draw_crop_mark(layer, x1, y0, 1., -1.);
+                    self.draw_crop_mark(layer, x1, y1, 1., 1.);
+                }
+            }
+        }
+
+        if self.layout.draw_cut_grid {
+            let (left, bottom) = self.card_origin_mm(0, 0);
+            let right = left + 3. * self.layout.card_width_mm
+                + 2. * self.layout.margin_between_cards_mm;
+            let top = bottom
+                + 3. * self.layout.card_height_mm
+                + 2. * self.layout.margin_between_cards_mm;
+
+            for col in 1..3 {
+                let (card_x, _) = self.card_origin_mm(0, col);
+                let x = card_x - self.layout.margin_between_cards_mm / 2.;
+                self.draw_line(layer, x, bottom, x, top);
+            }
+            for row in 1..3 {
+                let (_, card_y) = self.card_origin_mm(row, 0);
+                let y = card_y - self.layout.margin_between_cards_mm / 2.;
+                self.draw_line(layer, left, y, right, y);
+            }
+        }
+    }
+
+    /// Draws one L-shaped crop mark just outside the card corner at
+    /// `(x, y)`, extending away from the card in the direction
+    /// `(dir_x, dir_y)` (each `1.` or `-1.`) so the mark lands in the
+    /// surrounding margin or inter-card gap instead of over the art.
+    fn draw_crop_mark(&self, layer: &PdfLayerReference, x: f32, y: f32, dir_x: f32, dir_y: f32) {
+        let len = Self::CROP_MARK_LEN_MM;
+        self.draw_line(layer, x, y + dir_y * len, x, y);
+        self.draw_line(layer, x + dir_x * len, y, x, y);
+    }
+
+    /// Draws a single straight line segment on `layer` from `(x0, y0)` to
+    /// `(x1, y1)`, in millimeters.
+    fn draw_line(&self, layer: &PdfLayerReference, x0: f32, y0: f32, x1: f32, y1: f32) {
+        layer.add_shape(Line {
+            points: vec![
+                (Point::new(Mm(x0), Mm(y0)), false),
+                (Point::new(Mm(x1), Mm(y1)), false),
+            ],
+            is_closed: false,
+            has_fill: false,
+            has_stroke: true,
+            is_clipping_path: false,
+        });
+    }
+
+    /// Lays out one back page matching the front page's card slots.
+    /// Columns are mirrored (`2 - col`) so the back of each card lines up
+    /// with its front when the sheet is flipped on its long edge.
+    fn emit_back_page(
+        &self,
+        page_num: usize,
+        slots: &[(usize, usize, String, Option<Vec<u8>>)],
+        default_back_image: &[u8],
+        buf: &mut Vec<u8>,
+        report: &mut ProxyReport,
+    ) -> anyhow::Result<()> {
+        let (page_idx, layer_idx) = self.pdf.add_page(
+            Mm(Self::PAGE_WIDTH_MM),
+            Mm(Self::PAGE_HEIGHT_MM),
+            format!("page{page_num}-back"),
+        );
+        let layer = self.pdf.get_page(page_idx).get_layer(layer_idx);
+        self.draw_guides(&layer);
+
+        for (row, col, card_name, back_bytes) in slots {
+            let bytes: &[u8] = back_bytes.as_deref().unwrap_or(default_back_image);
+            let image = match self.make_image(Cursor::new(bytes), buf) {
+                Ok(image) => image,
+                Err(e) => {
+                    report.push(CardFailure {
+                        card_name: format!("{card_name} (back)"),
+                        error: ProxyError::UnsupportedFormat {
+                            detected: e.to_string(),
+                        },
+                    });
+                    continue;
+                }
+            };
+            let mirrored_col = 2 - col;
+            self.add_card_to_layer(image, layer.clone(), *row, mirrored_col);
+        }
+
+        Ok(())
+    }
+
+    /// Raster extensions `make_image` can decode. This is driven entirely
+    /// by the `image` crate's format registry, so anything it supports
+    /// lands here too.
+    const SUPPORTED_IMAGE_EXTENSIONS: &'static [&'static str] = &[
+        "jpg", "jpeg", "png", "gif", "webp", "bmp", "tiff", "tif", "avif", "ico", "pnm", "dds",
+        "tga", "qoi", "svg",
+    ];
+
+    /// Lists the raster image extensions this tool accepts, for CLI help output.
+    pub fn supported_image_extensions() -> &'static [&'static str] {
+        Self::SUPPORTED_IMAGE_EXTENSIONS
+    }
+
+    /// Decodes `image_bytes` into a `printpdf::Image` using the `image`
+    /// crate's generic decoder rather than matching a hard-coded set of
+    /// formats: whatever the `image` crate can open, this can place on a
+    /// proxy page.
+    fn make_image(&self, mut image_bytes: impl Read, buf: &mut Vec<u8>) -> anyhow::Result<Image> {
+        use image::ImageReader;
+
+        buf.clear();
+        image_bytes.read_to_end(buf)?;
+
+        if Self::looks_like_svg(buf) {
+            return self.rasterize_svg(buf);
+        }
+
+        let reader = ImageReader::new(Cursor::new(buf.as_slice())).with_guessed_format()?;
+        let format = reader
+            .format()
+            .ok_or_else(|| anyhow!("Unable to detect this image's format"))?;
+        let decoded = reader
+            .decode()
+            .map_err(|e| anyhow!("failed to decode {format:?} image: {e}"))?;
+
+        let width = decoded.width() as usize;
+        let height = decoded.height() as usize;
+        let (color_space, image_data) = if decoded.color().has_alpha() {
+            (ColorSpace::Rgba, decoded.into_rgba8().into_raw())
+        } else {
+            (ColorSpace::Rgb, decoded.into_rgb8().into_raw())
+        };
+
+        Ok(Image {
+            image: ImageXObject {
+                width: Px(width),
+                height: Px(height),
+                color_space,
+                bits_per_component: ColorBits::Bit8,
+                interpolate: true,
+                image_data,
+                image_filter: None,
+                clipping_bbox: None,
+            },
+        })
+    }
+
+    /// Sniffs for inline SVG content. Scryfall serves card/symbol art as
+    /// SVG for some sources, and the `image` crate has no decoder for it,
+    /// so this has to be routed around the raster path entirely.
+    fn looks_like_svg(bytes: &[u8]) -> bool {
+        let head = &bytes[..bytes.len().min(1024)];
+        String::from_utf8_lossy(head).contains("<svg")
+    }
+
+    /// Converts a millimeter length to a pixel count at `Self::DPI`.
+    fn mm_to_px(mm: f32) -> u32 {
+        (mm * Self::DPI / 25.4).round() as u32
+    }
+
+    /// Renders SVG source straight to a pixel buffer sized exactly for one
+    /// card at `Self::DPI`, so the page layout's usual mm->scale math comes
+    /// out to 1.0 and the vector art stays crisp instead of being resampled
+    /// twice.
+    fn rasterize_svg(&self, svg_bytes: &[u8]) -> anyhow::Result<Image> {
+        use resvg::{
+            tiny_skia::Pixmap,
+            usvg::{Options, Transform, Tree},
+        };
+
+        let tree = Tree::from_data(svg_bytes, &Options::default())
+            .map_err(|e| anyhow!("failed to parse svg: {e}"))?;
+
+        let px_width = Self::mm_to_px(self.layout.card_width_mm);
+        let px_height = Self::mm_to_px(self.layout.card_height_mm);
+
+        let mut pixmap = Pixmap::new(px_width, px_height)
+            .ok_or_else(|| anyhow!("failed to allocate a {px_width}x{px_height} svg pixmap"))?;
+
+        // Map the SVG's own viewBox onto the card's pixel box exactly, so
+        // the render fills the card with no cropping or letterboxing.
+        let view_box = tree.size();
+        let transform = Transform::from_scale(
+            px_width as f32 / view_box.width(),
+            px_height as f32 / view_box.height(),
+        );
+        resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+        Ok(Image {
+            image: ImageXObject {
+                width: Px(px_width as usize),
+                height: Px(px_height as usize),
+                color_space: ColorSpace::Rgba,
+                bits_per_component: ColorBits::Bit8,
+                interpolate: true,
+                image_data: Self::unpremultiply_rgba(pixmap.data()),
+                image_filter: None,
+                clipping_bbox: None,
+            },
+        })
+    }
+
+    /// tiny_skia renders into premultiplied-alpha RGBA (each color channel
+    /// already scaled by alpha), but `ImageXObject`'s `ColorSpace::Rgba`
+    /// expects straight alpha, so this reverses that scaling per pixel
+    /// before the bytes leave `rasterize_svg`. Without it, translucent SVG
+    /// pixels come out with dark fringing in the final PDF.
+    fn unpremultiply_rgba(premultiplied: &[u8]) -> Vec<u8> {
+        let mut data = premultiplied.to_vec();
+        for pixel in data.chunks_exact_mut(4) {
+            let alpha = pixel[3] as u16;
+            if alpha == 0 {
+                continue;
+            }
+            for channel in &mut pixel[..3] {
+                *channel = (*channel as u16 * 255 / alpha) as u8;
+            }
+        }
+        data
+    }
 }
 
 impl Default for ProxyPdf {
     fn default() -> Self {
-        Self {
-            pdf: PdfDocument::empty("MTG deck proxy"),
-        }
+        Self::new(LayoutConfig::default())
     }
 }
 
 pub struct ProxyCsv {}
 
 impl ProxyCsv {
-    /// Queries scryfall for the image url associated with this MTG card
-    /// (if one can be found).
-    fn get_image_url_for_card_name(name: &str) -> anyhow::Result<String> {
+    /// Looks up the image url(s) for `name`, preferring a `lang` printing
+    /// when one is given. Returns whether the result fell back to English
+    /// art because the localized printing had none.
+    fn get_image_url_for_card_name(
+        name: &str,
+        lang: Option<&str>,
+    ) -> anyhow::Result<(CardArtUrls, bool)> {
+        if let Some(lang) = lang {
+            if let Some(urls) = Self::localized_image_url(name, lang)? {
+                return Ok((urls, false));
+            }
+        }
+        Ok((Self::english_image_url(name)?, lang.is_some()))
+    }
+
+    /// Queries scryfall's exact-name endpoint, which always returns
+    /// English art.
+    fn english_image_url(name: &str) -> anyhow::Result<CardArtUrls> {
         let url = Url::parse(&format!(
             "https://api.scryfall.com/cards/named?exact={name}"
         ))?;
+        let card_info = Self::scryfall_get(url)?;
+        Self::extract_image_urls(&card_info)
+            .ok_or_else(|| anyhow!("Failed to extract image url from json output"))
+    }
+
+    /// Searches scryfall's printing search for a `lang` printing of
+    /// `name`. Returns `Ok(None)` rather than erroring when the printing
+    /// exists but has no `normal` art, so the caller can fall back.
+    fn localized_image_url(name: &str, lang: &str) -> anyhow::Result<Option<CardArtUrls>> {
+        let mut url = Url::parse("https://api.scryfall.com/cards/search")?;
+        url.query_pairs_mut()
+            .append_pair("q", &format!("!\"{name}\" lang:{lang}"));
+        let results = Self::scryfall_get(url)?;
+
+        let Some(card_info) = results.get("data").and_then(|data| data.get(0)) else {
+            return Ok(None);
+        };
+        Ok(Self::extract_image_urls(card_info))
+    }
+
+    /// Pulls a card's front (and, for double-faced/transform cards, back)
+    /// art url out of a scryfall card object. Single-faced cards carry
+    /// `image_uris` at the top level; double-faced cards nest it under
+    /// `card_faces[0]`/`card_faces[1]` instead.
+    fn extract_image_urls(card_info: &Value) -> Option<CardArtUrls> {
+        let normal_url = |uris: &Value| {
+            uris.get("image_uris")
+                .and_then(|uris| uris.get("normal"))
+                .and_then(|val| val.as_str())
+                .map(str::to_string)
+        };
+
+        if let Some(front) = normal_url(card_info) {
+            return Some(CardArtUrls { front, back: None });
+        }
+
+        let faces = card_info.get("card_faces")?.as_array()?;
+        let front = normal_url(faces.first()?)?;
+        let back = faces.get(1).and_then(normal_url);
+        Some(CardArtUrls { front, back })
+    }
 
+    fn scryfall_get(url: Url) -> anyhow::Result<Value> {
         let client = blocking::Client::new();
-        let card_info: Value = client
+        Ok(client
             .get(url)
             .header(USER_AGENT, "MyCliProxyFormatter/1.0")
             .header(ACCEPT, "*/*")
             .send()?
-            .json()?;
-
-        let image_url = card_info
-            .get("image_uris")
-            .and_then(|uris| uris.get("normal"))
-            .and_then(|val| val.as_str())
-            .ok_or_else(|| anyhow!("Failed to extract image url from json output"))?;
-        Ok(image_url.to_string())
+            .json()?)
     }
 
     /// Parses a manabox-style text file into a CSV usable by pdf gen.
-    pub fn csv_from_txt(input_txt: &Path, output_csv: &Path) -> anyhow::Result<()> {
+    /// When `lang` is given, art is fetched in that language where
+    /// Scryfall has a printing for it; cards whose localized printing
+    /// lacks art fall back to English and are noted in the report. Lines
+    /// that don't parse and names Scryfall can't find are recorded in the
+    /// returned report rather than silently dropped.
+    pub fn csv_from_txt(
+        input_txt: &Path,
+        output_csv: &Path,
+        lang: Option<&str>,
+    ) -> anyhow::Result<ProxyReport> {
         let mut out = csv::Writer::from_path(output_csv)?;
+        let mut report = ProxyReport::default();
         for line in BufReader::new(File::open(input_txt)?).lines() {
             let line = line?;
             let mut words = line.trim().splitn(2, ' ');
@@ -170,54 +700,212 @@ impl ProxyCsv {
                 .and_then(|word| word.parse::<usize>().ok())
                 .and_then(|ct| name.map(|n| (ct, n)))
             else {
-                println!("skipping: {line}");
+                report.push(CardFailure {
+                    card_name: line.clone(),
+                    error: ProxyError::MalformedRow { line },
+                });
                 continue;
             };
 
-            let image_url = Self::get_image_url_for_card_name(name).unwrap_or_else(|e| {
-                eprintln!("Image fetch failed: {e:?}");
-                String::new()
-            });
+            let (art_urls, used_fallback) = match Self::get_image_url_for_card_name(name, lang) {
+                Ok(result) => result,
+                Err(e) => {
+                    eprintln!("Scryfall lookup failed for \"{name}\": {e:?}");
+                    report.push(CardFailure {
+                        card_name: name.to_string(),
+                        error: ProxyError::CardNotFound {
+                            name: name.to_string(),
+                        },
+                    });
+                    continue;
+                }
+            };
+            if used_fallback {
+                report.push_notice(CardFailure {
+                    card_name: name.to_string(),
+                    error: ProxyError::LocalizedFallback {
+                        name: name.to_string(),
+                        lang: lang.expect("fallback only set when lang was requested").to_string(),
+                    },
+                });
+            }
 
-            println!("adding x{count} {name} at {image_url}");
+            println!("adding x{count} {name} at {}", art_urls.front);
             out.serialize(DeckCsvRow {
                 count,
                 card_name: name.to_string(),
-                image_url,
+                image_url: art_urls.front,
+                back_image_url: art_urls.back,
             })?;
         }
-        Ok(())
+        Ok(report)
+    }
+
+    /// Local directory that downloaded card art is cached under, keyed by
+    /// a hash of its source URL.
+    const IMAGE_CACHE_DIR: &'static str = ".print_proxy_cache";
+
+    /// How many fetch workers run at once. Scryfall and host CDNs are the
+    /// bottleneck, not this process, so there's no benefit past a handful
+    /// of concurrent requests.
+    const FETCH_POOL_SIZE: usize = 8;
+
+    /// Reads `url`'s bytes from the on-disk cache, or fetches and caches
+    /// them on a miss.
+    fn fetch_cached(url: &str, cache_dir: &Path) -> Result<Arc<Vec<u8>>, ProxyError> {
+        let cache_path = Self::cache_path_for_url(cache_dir, url);
+        if let Ok(bytes) = fs::read(&cache_path) {
+            return Ok(Arc::new(bytes));
+        }
+
+        let response = blocking::get(url).map_err(|e| ProxyError::ImageFetch {
+            url: url.to_string(),
+            status: e.to_string(),
+        })?;
+        if !response.status().is_success() {
+            return Err(ProxyError::ImageFetch {
+                url: url.to_string(),
+                status: response.status().to_string(),
+            });
+        }
+        let bytes = response
+            .bytes()
+            .map_err(|e| ProxyError::ImageFetch {
+                url: url.to_string(),
+                status: e.to_string(),
+            })?
+            .to_vec();
+        // The cache is a best-effort speedup; a write failure shouldn't
+        // fail a card that we already successfully fetched.
+        let _ = fs::write(&cache_path, &bytes);
+        Ok(Arc::new(bytes))
     }
 
-    /// Iterates the rows of the CSV, yielding one image buffer per card
-    /// required in the deck.
-    /// This isn't very memory efficient, but we don't really need that here.
+    fn cache_path_for_url(cache_dir: &Path, url: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        cache_dir.join(format!("{:016x}", hasher.finish()))
+    }
+
+    /// Iterates the rows of the CSV, yielding each card's name paired with
+    /// one image buffer per copy required in the deck.
+    ///
+    /// URLs are deduplicated and fetched across a bounded pool of worker
+    /// threads, with each download cached on disk by a hash of its URL so
+    /// re-running the same deck reads from disk instead of the network.
+    /// Fetched bytes are shared as `Arc<Vec<u8>>` so handing out `count`
+    /// copies per card is just a refcount bump. Malformed rows and failed
+    /// fetches are collected into the returned `Vec<CardFailure>` instead
+    /// of silently vanishing; the iterator itself borrows nothing, so
+    /// callers are free to merge those failures into their own
+    /// `ProxyReport` before driving the iterator through `gen_pdf`.
     pub fn iter_csv_images<R: Read>(
         csv_reader: &mut csv::Reader<R>,
-    ) -> anyhow::Result<impl Iterator<Item = Cursor<Vec<u8>>> + '_> {
-        let results = csv_reader
-            .deserialize()
-            .map_while(|row| {
-                let row: DeckCsvRow = row
-                    .inspect_err(|e| eprintln!("Malformed csv row: {e:?}"))
-                    .ok()?;
-                println!("{row:?}");
-                let fetched_image = blocking::get(&row.image_url)
-                    .inspect_err(|e| eprintln!("image fetch failed: {e:?}"))
-                    .ok()?;
-                if !fetched_image.status().is_success() {
-                    eprintln!("fetch failed: {:?}", fetched_image.status());
-                    return None;
-                }
-                let bytes = fetched_image
-                    .bytes()
-                    .inspect_err(|e| eprintln!("failed to fetch response bytes: {e:?}"))
-                    .ok()?
-                    .to_vec();
+    ) -> anyhow::Result<(
+        impl Iterator<Item = (String, Cursor<Arc<Vec<u8>>>, Option<Cursor<Arc<Vec<u8>>>>)>,
+        Vec<CardFailure>,
+    )> {
+        let cache_dir = Path::new(Self::IMAGE_CACHE_DIR);
+        fs::create_dir_all(cache_dir)?;
+
+        let mut failures = Vec::new();
 
-                Some((0..row.count).map(move |_| Cursor::new(bytes.clone())))
+        let rows: Vec<DeckCsvRow> = csv_reader
+            .deserialize()
+            .filter_map(|row| {
+                row.inspect_err(|e| eprintln!("Malformed csv row: {e:?}"))
+                    .map_err(|e| {
+                        failures.push(CardFailure {
+                            card_name: "<malformed row>".to_string(),
+                            error: ProxyError::MalformedRow {
+                                line: e.to_string(),
+                            },
+                        })
+                    })
+                    .ok()
             })
-            .flatten();
-        Ok(results)
+            .collect();
+
+        let mut seen_urls = HashSet::new();
+        let unique_urls: Vec<String> = rows
+            .iter()
+            .flat_map(|row| [Some(row.image_url.clone()), row.back_image_url.clone()])
+            .flatten()
+            .filter(|url| seen_urls.insert(url.clone()))
+            .collect();
+
+        let chunk_size = unique_urls.len().div_ceil(Self::FETCH_POOL_SIZE).max(1);
+        let (fetched, fetch_errors): (HashMap<_, _>, HashMap<_, _>) = thread::scope(|scope| {
+            let handles: Vec<_> = unique_urls
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(|| {
+                        chunk
+                            .iter()
+                            .map(|url| (url.clone(), Self::fetch_cached(url, cache_dir)))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("fetch worker panicked"))
+                .partition(|(_, result)| result.is_ok())
+        });
+        let fetched: HashMap<String, Arc<Vec<u8>>> = fetched
+            .into_iter()
+            .map(|(url, result)| (url, result.expect("partitioned as Ok")))
+            .collect();
+        let fetch_errors: HashMap<String, ProxyError> = fetch_errors
+            .into_iter()
+            .map(|(url, result)| (url, result.expect_err("partitioned as Err")))
+            .collect();
+
+        let mut results = Vec::new();
+        for row in rows {
+            println!("{row:?}");
+            let front_bytes = match fetched.get(&row.image_url) {
+                Some(bytes) => bytes.clone(),
+                None => {
+                    let error = fetch_errors
+                        .get(&row.image_url)
+                        .map(|e| e.to_string())
+                        .unwrap_or_else(|| "unknown fetch error".to_string());
+                    failures.push(CardFailure {
+                        card_name: row.card_name,
+                        error: ProxyError::ImageFetch {
+                            url: row.image_url,
+                            status: error,
+                        },
+                    });
+                    continue;
+                }
+            };
+            let back_bytes = row.back_image_url.as_ref().and_then(|url| {
+                fetched.get(url).cloned().or_else(|| {
+                    let error = fetch_errors
+                        .get(url)
+                        .map(|e| e.to_string())
+                        .unwrap_or_else(|| "unknown fetch error".to_string());
+                    failures.push(CardFailure {
+                        card_name: format!("{} (back)", row.card_name),
+                        error: ProxyError::ImageFetch {
+                            url: url.clone(),
+                            status: error,
+                        },
+                    });
+                    None
+                })
+            });
+            let card_name = row.card_name;
+            results.extend((0..row.count).map(|_| {
+                (
+                    card_name.clone(),
+                    Cursor::new(front_bytes.clone()),
+                    back_bytes.clone().map(Cursor::new),
+                )
+            }));
+        }
+        Ok((results.into_iter(), failures))
     }
 }